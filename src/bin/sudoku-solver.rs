@@ -3,16 +3,11 @@ extern crate sudoku_solver as ss;
 use ss::Board;
 
 fn main() {
-    let data = std::env::args()
-        .skip(1)
-        .flat_map(|arg| arg.chars().collect::<Vec<_>>())
-        .filter_map(|c| c.to_digit(10).map(|i| i as u8))
-        .collect::<Vec<_>>();
+    let input = std::env::args().skip(1).collect::<Vec<_>>().join(" ");
 
-    let cells = data.get(..(Board::DSIZE * Board::DSIZE))
-        .expect("Incomplete board");
-
-    let board = Board::from_values(cells)
+    let board = input
+        .parse::<Board<3>>()
+        .expect("Invalid board")
         .solve()
         .expect("Failed to solve board");
 
@@ -1,44 +1,66 @@
-use std::ops::{BitAnd, BitAndAssign, Not};
+use std::ops::{BitAnd, BitAndAssign, BitOr, Not};
 use fmt::{Debug, Formatter};
 use std::char::from_digit;
 use std::fmt::{Write, Display};
 use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
 
-const SIZE: usize = 3;
-const DSIZE: usize = SIZE * SIZE;
+mod strategies;
 
-/// Sudoku cell
+/// Sudoku cell. `SIZE` is the board's box dimension (`3` for classic 9×9,
+/// `4` for 16×16, ...); candidates are tracked as a `SIZE*SIZE`-bit mask.
 #[derive(Copy, Clone, PartialEq, Eq)]
-struct Cell(u16);
+struct Cell<const SIZE: usize = 3>(u128);
+
+/// Sudoku board. `SIZE` is the box dimension; the board is `SIZE*SIZE` cells
+/// wide and tall, e.g. `Board<3>` is the classic 9×9 board, `Board<4>` is
+/// 16×16, and `Board<5>` is 25×25. `SIZE*SIZE` must be at most `35`, since
+/// `Debug`/`Display` print each value as a single base-36 glyph.
+///
+/// Alongside the always-present rows and columns, every board carries a
+/// third group of units (classically the `SIZE×SIZE` boxes) that can be
+/// swapped out via [`Board::with_units`] for variants such as X-sudoku
+/// (boxes plus the two diagonals), jigsaw sudoku (irregular regions in
+/// place of the boxes), or other "no two cells in a unit repeat" rules.
+#[derive(Clone)]
+pub struct Board<const SIZE: usize>(Box<[Cell<SIZE>]>, Rc<[Vec<usize>]>);
+
+impl<const SIZE: usize> PartialEq for Board<SIZE> {
+    /// Compares cell contents only; two boards with the same grid are equal
+    /// even if they carry different (or differently ordered) unit sets.
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
 
-/// Sudoku board
-#[derive(Clone, PartialEq, Eq)]
-pub struct Board(Box<[Cell]>);
+impl<const SIZE: usize> Eq for Board<SIZE> {}
 
 /// Iterator yielding all possible candidate for a `Cell` as new `Cell`s.
 #[derive(Debug)]
-struct Guesses(Cell, usize);
+struct Guesses<const SIZE: usize>(Cell<SIZE>, usize);
 
-impl Cell {
-    const MASK: u16 = (1u16 << DSIZE) - 1;
+impl<const SIZE: usize> Cell<SIZE> {
+    const DSIZE: usize = SIZE * SIZE;
+    const MASK: u128 = (1u128 << Self::DSIZE) - 1;
 
     /// Constructs a new `Cell` with no candidates.
-    const fn none() -> Cell {
+    const fn none() -> Cell<SIZE> {
         Cell(0)
     }
 
-    /// Constructs a new `Cell` with all values (1..=9) as candidates.
-    const fn all() -> Cell {
-        Cell(Cell::MASK & <u16>::max_value())
+    /// Constructs a new `Cell` with all values (1..=DSIZE) as candidates.
+    const fn all() -> Cell<SIZE> {
+        Cell(Cell::<SIZE>::MASK)
     }
 
-    /// Constructs a new `Cell` from bits with each bit 0..9 representing a possible candidate 1..=9.
-    const fn from_bits(bits: u16) -> Cell {
-        Cell(Cell::MASK & bits)
+    /// Constructs a new `Cell` from bits with each bit 0..DSIZE representing a possible candidate 1..=DSIZE.
+    const fn from_bits(bits: u128) -> Cell<SIZE> {
+        Cell(Cell::<SIZE>::MASK & bits)
     }
 
     /// Returns an iterator yielding all possible candidate for this cell as new `Cell`s.
-    const fn guesses(&self) -> Guesses {
+    const fn guesses(&self) -> Guesses<SIZE> {
         Guesses(*self, 0)
     }
 
@@ -47,11 +69,11 @@ impl Cell {
         self.0.count_ones()
     }
 
-    /// Constructs a new `Cell` from value (0..=9).
-    fn from_value(value: u8) -> Cell {
+    /// Constructs a new `Cell` from value (0..=DSIZE).
+    fn from_value(value: u8) -> Cell<SIZE> {
         match value {
             0 => Cell::all(),
-            i if i as usize > DSIZE => panic!("Cell value must be less then {}", DSIZE),
+            i if i as usize > Self::DSIZE => panic!("Cell value must be at most {}", Self::DSIZE),
             i => Cell(1 << (i - 1)),
         }
     }
@@ -69,7 +91,7 @@ impl Cell {
     }
 
     /// Update possible candidates based on the value a cell.
-    fn update_candidates(&mut self, cell: Cell) -> Result<(), ()> {
+    fn update_candidates(&mut self, cell: Cell<SIZE>) -> Result<(), ()> {
         if cell.num_candidates() == 1 {
             if !*self & cell != Cell::none() {
                 return Err(());
@@ -80,7 +102,7 @@ impl Cell {
     }
 
     /// Update possible candidates for a cell based on candidates.
-    fn update_cell(&mut self, candidates: Cell) -> Result<bool, ()> {
+    fn update_cell(&mut self, candidates: Cell<SIZE>) -> Result<bool, ()> {
         if self.num_candidates() != 1 {
             let prev = *self;
             *self &= candidates;
@@ -94,39 +116,46 @@ impl Cell {
     }
 }
 
-impl BitAnd for Cell {
+impl<const SIZE: usize> BitAnd for Cell<SIZE> {
     type Output = Self;
     fn bitand(self, rhs: Self) -> Self::Output {
         Cell(self.0 & rhs.0)
     }
 }
 
-impl BitAndAssign for Cell {
+impl<const SIZE: usize> BitAndAssign for Cell<SIZE> {
     fn bitand_assign(&mut self, rhs: Self) {
         self.0 &= rhs.0;
     }
 }
 
-impl Not for Cell {
-    type Output = Cell;
+impl<const SIZE: usize> BitOr for Cell<SIZE> {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Cell(self.0 | rhs.0)
+    }
+}
+
+impl<const SIZE: usize> Not for Cell<SIZE> {
+    type Output = Cell<SIZE>;
     fn not(self) -> Self::Output {
-        Cell(Cell::MASK & !self.0)
+        Cell(Cell::<SIZE>::MASK & !self.0)
     }
 }
 
-impl Debug for Cell {
+impl<const SIZE: usize> Debug for Cell<SIZE> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_tuple("Cell")
-            .field(&format!("{:#09b}", self.0))
+            .field(&format!("{:#b}", self.0))
             .finish()
     }
 }
 
-impl Iterator for Guesses {
-    type Item = Cell;
+impl<const SIZE: usize> Iterator for Guesses<SIZE> {
+    type Item = Cell<SIZE>;
 
-    fn next(&mut self) -> Option<Cell> {
-        while self.1 < DSIZE {
+    fn next(&mut self) -> Option<Cell<SIZE>> {
+        while self.1 < Cell::<SIZE>::DSIZE {
             if ((self.0).0 & 1 << self.1) != 0 {
                 let s = Some(Cell(1 << self.1));
                 self.1 += 1;
@@ -138,13 +167,69 @@ impl Iterator for Guesses {
     }
 }
 
-impl Board {
+/// Blank markers accepted by [`FromStr`] when parsing a [`Board`].
+const DEFAULT_BLANKS: [char; 3] = ['0', '.', '_'];
+
+/// Error returned when parsing a [`Board`] from text fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBoardError {
+    /// The input did not contain exactly `Board::DSIZE * Board::DSIZE` cells.
+    WrongLength { found: usize },
+    /// A character was neither a digit, a blank marker, nor whitespace.
+    InvalidChar { char: char },
+    /// A digit was present but outside the valid `1..=DSIZE` range.
+    OutOfRange { digit: u8 },
+}
+
+impl Display for ParseBoardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseBoardError::WrongLength { found } => {
+                write!(f, "board must have {} cells, found {}", Board::<3>::DSIZE * Board::<3>::DSIZE, found)
+            }
+            ParseBoardError::InvalidChar { char } => {
+                write!(f, "unexpected character {:?} in board", char)
+            }
+            ParseBoardError::OutOfRange { digit } => {
+                write!(f, "digit {} is out of range 1..={}", digit, Board::<3>::DSIZE)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseBoardError {}
+
+impl FromStr for Board<3> {
+    type Err = ParseBoardError;
+
+    /// Parses a board from text, accepting `0`, `.`, or `_` as blank cells,
+    /// ignoring any whitespace, and skipping lines that carry no cell data
+    /// at all, so a grid pasted as nine rows parses correctly whether or
+    /// not it's decorated with ASCII-art separator lines between blocks.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Board::from_str_with(s, &DEFAULT_BLANKS)
+    }
+}
+
+impl<const SIZE: usize> Board<SIZE> {
     pub const SIZE: usize = SIZE;
-    pub const DSIZE: usize = DSIZE;
+    pub const DSIZE: usize = SIZE * SIZE;
+
+    /// Panics if `DSIZE` is too large for a cell value to render as a single
+    /// base-36 glyph (`1`-`9`, then `a`-`z`), which is what `Debug`/`Display`
+    /// use to print a solved cell.
+    fn check_size() {
+        assert!(
+            Self::DSIZE <= 35,
+            "Board<{SIZE}> has {} values per unit, but only sizes with SIZE*SIZE <= 35 can be rendered",
+            Self::DSIZE,
+        );
+    }
 
-    pub fn from_values(data: &[u8]) -> Board {
-        if data.len() != DSIZE * DSIZE {
-            panic!("Board must have {} cells", DSIZE * DSIZE)
+    pub fn from_values(data: &[u8]) -> Board<SIZE> {
+        Self::check_size();
+        if data.len() != Self::DSIZE * Self::DSIZE {
+            panic!("Board must have {} cells", Self::DSIZE * Self::DSIZE)
         }
 
         let cells = data
@@ -153,12 +238,13 @@ impl Board {
             .collect::<Vec<_>>()
             .into_boxed_slice();
 
-        Board(cells)
+        Board(cells, Rc::from(Self::classic_units()))
     }
 
-    pub fn from_bits(data: &[u16]) -> Board {
-        if data.len() != DSIZE * DSIZE {
-            panic!("Board must have {} cells", DSIZE * DSIZE)
+    pub fn from_bits(data: &[u128]) -> Board<SIZE> {
+        Self::check_size();
+        if data.len() != Self::DSIZE * Self::DSIZE {
+            panic!("Board must have {} cells", Self::DSIZE * Self::DSIZE)
         }
 
         let cells = data
@@ -167,12 +253,49 @@ impl Board {
             .collect::<Vec<_>>()
             .into_boxed_slice();
 
-        Board(cells)
+        Board(cells, Rc::from(Self::classic_units()))
+    }
+
+    /// Constructs a board from cell values using a custom third group of
+    /// units in place of the classic boxes, e.g. the two diagonals plus
+    /// the boxes for X-sudoku, or irregular regions for jigsaw sudoku.
+    /// Rows and columns are always enforced in addition to these units.
+    pub fn with_units(data: &[u8], units: Vec<Vec<usize>>) -> Board<SIZE> {
+        Self::check_size();
+        if data.len() != Self::DSIZE * Self::DSIZE {
+            panic!("Board must have {} cells", Self::DSIZE * Self::DSIZE)
+        }
+
+        let cells = data
+            .iter()
+            .map(|i| Cell::from_value(*i))
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        Board(cells, Rc::from(units))
+    }
+
+    /// The classic `SIZE×SIZE` boxes, in box-major order.
+    fn classic_units() -> Vec<Vec<usize>> {
+        let dsize = Self::DSIZE;
+        let mut units = Vec::with_capacity(SIZE * SIZE);
+
+        for sy in 0..SIZE {
+            for sx in 0..SIZE {
+                units.push(
+                    (0..SIZE)
+                        .flat_map(|y| (0..SIZE).map(move |x| (sy * SIZE + y) * dsize + sx * SIZE + x))
+                        .collect(),
+                );
+            }
+        }
+
+        units
     }
 
     fn solve_rows(&mut self) -> Result<bool, ()> {
         let mut changed = false;
-        for row in self.0.chunks_mut(DSIZE) {
+        for row in self.0.chunks_mut(Self::DSIZE) {
             let mut candidates = Cell::all();
 
             for cell in row.iter() {
@@ -189,16 +312,16 @@ impl Board {
 
     fn solve_columns(&mut self) -> Result<bool, ()> {
         let mut changed = false;
-        for x in 0..DSIZE {
+        for x in 0..Self::DSIZE {
             let mut candidates = Cell::all();
 
-            for y in 0..DSIZE {
-                let cell = self.0[y * DSIZE + x];
+            for y in 0..Self::DSIZE {
+                let cell = self.0[y * Self::DSIZE + x];
                 candidates.update_candidates(cell)?;
             }
 
-            for y in 0..DSIZE {
-                let cell = &mut self.0[y * DSIZE + x];
+            for y in 0..Self::DSIZE {
+                let cell = &mut self.0[y * Self::DSIZE + x];
                 changed |= cell.update_cell(candidates)?;
             }
         }
@@ -206,25 +329,21 @@ impl Board {
         Ok(changed)
     }
 
+    /// Propagates the board's configured unit set (the classic boxes,
+    /// unless [`Board::with_units`] was given something else).
     fn solve_squares(&mut self) -> Result<bool, ()> {
         let mut changed = false;
-        for sy in 0..SIZE {
-            for sx in 0..SIZE {
-                let mut candidates = Cell::all();
+        let units = Rc::clone(&self.1);
 
-                for y in 0..SIZE {
-                    for x in 0..SIZE {
-                        let cell = self.0[(sy * SIZE + y) * DSIZE + sx * SIZE + x];
-                        candidates.update_candidates(cell)?;
-                    }
-                }
+        for unit in units.iter() {
+            let mut candidates = Cell::all();
 
-                for y in 0..SIZE {
-                    for x in 0..SIZE {
-                        let cell = &mut self.0[(sy * SIZE + y) * DSIZE + sx * SIZE + x];
-                        changed |= cell.update_cell(candidates)?;
-                    }
-                }
+            for &idx in unit {
+                candidates.update_candidates(self.0[idx])?;
+            }
+
+            for &idx in unit {
+                changed |= self.0[idx].update_cell(candidates)?;
             }
         }
 
@@ -232,7 +351,13 @@ impl Board {
     }
 
     fn solve_all(&mut self) -> Result<(), ()> {
-        while self.solve_rows()? | self.solve_columns()? | self.solve_squares()? {}
+        while self.solve_rows()?
+            | self.solve_columns()?
+            | self.solve_squares()?
+            | self.hidden_singles()?
+            | self.naked_subsets()?
+            | self.pointing_pairs()?
+        {}
         Ok(())
     }
 
@@ -240,52 +365,366 @@ impl Board {
         self.0.iter().all(|cell| cell.num_candidates() == 1)
     }
 
-    pub fn solve(mut self) -> Result<Self, Self> {
+    /// Solves as far as possible using only logical deduction (propagation
+    /// plus the hidden-single, naked-subset, and pointing-pair strategies),
+    /// without falling back to backtracking guesses. Returns `Err` if the
+    /// board is not fully solved by logic alone, or if it is contradictory.
+    pub fn solve_logical(mut self) -> Result<Self, Self> {
         match self.solve_all() {
             Ok(()) => (),
             Err(()) => return Err(self),
         }
 
         if self.solved() {
-            return Ok(self);
+            Ok(self)
+        } else {
+            Err(self)
         }
+    }
 
-        for i in 0..(DSIZE * DSIZE) {
+    /// Finds the undetermined cell with the fewest remaining candidates
+    /// (minimum-remaining-value), which prunes the backtracking search tree
+    /// the most. Returns `Err` if some cell has no candidates left.
+    fn best_branch_cell(&self) -> Result<usize, ()> {
+        let mut best: Option<(usize, u32)> = None;
+        for i in 0..(Self::DSIZE * Self::DSIZE) {
             match self.0[i].num_candidates() {
-                0 => unreachable!(),
+                0 => return Err(()),
                 1 => (),
-                _ => {
-                    for guess in self.0[i].guesses() {
-                        let mut new = self.clone();
-                        new.0[i] = guess;
-                        match new.solve() {
-                            // We found a solution
-                            Ok(board) => return Ok(board),
-                            Err(_) => {
-                                // We hit an error, we now know that
-                                // this is not valid value for this cell.
-                                self.0[i] &= !guess;
-                            }
-                        }
+                n => {
+                    if best.is_none_or(|(_, best_n)| n < best_n) {
+                        best = Some((i, n));
                     }
                 }
             }
         }
+
+        match best {
+            Some((i, _)) => Ok(i),
+            None => unreachable!(),
+        }
+    }
+
+    pub fn solve(mut self) -> Result<Self, Self> {
+        match self.solve_all() {
+            Ok(()) => (),
+            Err(()) => return Err(self),
+        }
+
+        if self.solved() {
+            return Ok(self);
+        }
+
+        let i = match self.best_branch_cell() {
+            Ok(i) => i,
+            Err(()) => return Err(self),
+        };
+
+        for guess in self.0[i].guesses() {
+            let mut new = self.clone();
+            new.0[i] = guess;
+            match new.solve() {
+                // We found a solution
+                Ok(board) => return Ok(board),
+                Err(_) => {
+                    // We hit an error, we now know that
+                    // this is not valid value for this cell.
+                    self.0[i] &= !guess;
+                }
+            }
+        }
         Err(self)
     }
+
+    /// Counts distinct solutions, stopping early once `limit` are found.
+    /// Useful for validating that a puzzle has a unique solution without
+    /// paying the cost of enumerating every solution of an ambiguous one.
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        let mut count = 0;
+        self.clone().count_solutions_into(limit, &mut count);
+        count
+    }
+
+    fn count_solutions_into(mut self, limit: usize, count: &mut usize) {
+        if *count >= limit {
+            return;
+        }
+
+        match self.solve_all() {
+            Ok(()) => (),
+            Err(()) => return,
+        }
+
+        if self.solved() {
+            *count += 1;
+            return;
+        }
+
+        let i = match self.best_branch_cell() {
+            Ok(i) => i,
+            Err(()) => return,
+        };
+
+        for guess in self.0[i].guesses() {
+            if *count >= limit {
+                return;
+            }
+            let mut new = self.clone();
+            new.0[i] = guess;
+            new.count_solutions_into(limit, count);
+        }
+    }
+
+    /// Returns `true` if the board has exactly one solution.
+    pub fn is_unique(&self) -> bool {
+        self.count_solutions(2) == 1
+    }
+
+    /// Solves via simulated annealing instead of exact propagation and
+    /// backtracking. Every already-fixed cell is treated as a clue; each
+    /// box is first filled with a random permutation of its missing values
+    /// so every box is internally valid, and the search then repeatedly
+    /// swaps two non-clue cells within a random box, accepting the swap if
+    /// it does not increase the number of row/column duplicates, or
+    /// otherwise with probability `exp(-delta/T)`. `T` cools geometrically
+    /// and the search restarts from a fresh random fill if it gets stuck.
+    ///
+    /// Always works against rows, columns, and the classic `SIZE×SIZE`
+    /// boxes, regardless of any unit set installed via [`Board::with_units`]
+    /// — permuting within boxes only keeps every candidate fill box-valid,
+    /// which doesn't hold for an arbitrary, possibly non-partitioning unit
+    /// set (e.g. X-sudoku's diagonals, or jigsaw regions that don't align
+    /// with box boundaries). Don't use this on a board whose solution
+    /// depends on a non-classic unit set; use [`Board::solve`] instead.
+    ///
+    /// Useful for very hard grids where backtracking thrashes, or for
+    /// studying near-solutions, at the cost of no longer guaranteeing that
+    /// a solution is found within the step budget.
+    pub fn solve_annealing(&self) -> Result<Self, Self> {
+        const INITIAL_TEMPERATURE: f64 = 0.5;
+        const COOLING_RATE: f64 = 0.9999;
+        const STEPS_PER_ATTEMPT: u32 = 50_000;
+        const MAX_ATTEMPTS: u32 = 20;
+
+        let dsize = Self::DSIZE;
+        let boxes = Self::classic_units();
+        let is_clue: Vec<bool> = self.0.iter().map(|cell| cell.num_candidates() == 1).collect();
+        let mut rng = Rng::seeded();
+
+        let to_board = |values: &[u8]| {
+            let cells = values
+                .iter()
+                .map(|&v| Cell::from_value(v))
+                .collect::<Vec<_>>()
+                .into_boxed_slice();
+            Board(cells, Rc::clone(&self.1))
+        };
+
+        for _ in 0..MAX_ATTEMPTS {
+            let mut values = self.fill_boxes(&boxes, &is_clue, &mut rng);
+            let mut energy = row_column_duplicates(&values, dsize);
+            let mut temperature = INITIAL_TEMPERATURE;
+
+            if energy == 0 {
+                return Ok(to_board(&values));
+            }
+
+            for _ in 0..STEPS_PER_ATTEMPT {
+                let unit = &boxes[rng.below(boxes.len())];
+                let free: Vec<usize> = unit.iter().copied().filter(|&i| !is_clue[i]).collect();
+                if free.len() < 2 {
+                    continue;
+                }
+
+                let i = free[rng.below(free.len())];
+                let mut j = free[rng.below(free.len())];
+                while j == i {
+                    j = free[rng.below(free.len())];
+                }
+
+                values.swap(i, j);
+                let new_energy = row_column_duplicates(&values, dsize);
+
+                let accept = new_energy <= energy
+                    || rng.next_f64() < (-((new_energy - energy) as f64) / temperature).exp();
+
+                if accept {
+                    energy = new_energy;
+                    if energy == 0 {
+                        return Ok(to_board(&values));
+                    }
+                } else {
+                    values.swap(i, j);
+                }
+
+                temperature *= COOLING_RATE;
+            }
+        }
+
+        Err(self.clone())
+    }
+
+    /// Fills every non-clue cell of each unit in `boxes` with a random
+    /// permutation of the values missing from that unit's clues.
+    fn fill_boxes(&self, boxes: &[Vec<usize>], is_clue: &[bool], rng: &mut Rng) -> Vec<u8> {
+        let dsize = Self::DSIZE;
+        let mut values: Vec<u8> = self
+            .0
+            .iter()
+            .map(|cell| cell.value().unwrap_or(0))
+            .collect();
+
+        for unit in boxes {
+            let used: Vec<u8> = unit
+                .iter()
+                .copied()
+                .filter(|&i| is_clue[i])
+                .map(|i| values[i])
+                .collect();
+            let mut missing: Vec<u8> = (1..=dsize as u8).filter(|v| !used.contains(v)).collect();
+            rng.shuffle(&mut missing);
+
+            let mut missing = missing.into_iter();
+            for i in unit.iter().copied().filter(|&i| !is_clue[i]) {
+                values[i] = missing.next().unwrap();
+            }
+        }
+
+        values
+    }
+}
+
+/// The number of duplicate values across all rows and all columns, i.e.
+/// `sum(DSIZE - distinct_count)` over every row and every column.
+fn row_column_duplicates(values: &[u8], dsize: usize) -> i64 {
+    let mut duplicates = 0i64;
+
+    for y in 0..dsize {
+        let mut seen = vec![false; dsize + 1];
+        let mut distinct = 0;
+        for x in 0..dsize {
+            let v = values[y * dsize + x] as usize;
+            if !seen[v] {
+                seen[v] = true;
+                distinct += 1;
+            }
+        }
+        duplicates += (dsize - distinct) as i64;
+    }
+
+    for x in 0..dsize {
+        let mut seen = vec![false; dsize + 1];
+        let mut distinct = 0;
+        for y in 0..dsize {
+            let v = values[y * dsize + x] as usize;
+            if !seen[v] {
+                seen[v] = true;
+                distinct += 1;
+            }
+        }
+        duplicates += (dsize - distinct) as i64;
+    }
+
+    duplicates
+}
+
+/// Minimal xorshift64 PRNG, so `Board::solve_annealing` doesn't need an
+/// external dependency just for random box permutations and acceptance
+/// sampling.
+struct Rng(u64);
+
+impl Rng {
+    fn seeded() -> Rng {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x9E3779B97F4A7C15);
+        Rng(seed | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// Returns a pseudo-random float in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a pseudo-random integer in `0..bound`.
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Fisher-Yates shuffle.
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
 }
 
-fn debug_line(f: &mut Formatter<'_>, start: char, line: char, cross: char, alt_cross: char, end: char) -> fmt::Result {
+impl Board<3> {
+    /// Parses a board from text using a custom set of blank markers.
+    ///
+    /// Whitespace is always treated as an ignorable separator unless a
+    /// whitespace character is itself included in `blanks`, in which case
+    /// it is counted as a blank cell instead. A line containing no digit
+    /// and no blank marker (e.g. an ASCII-art divider like
+    /// `------+-------+------` between 3x3 blocks) carries no cell data
+    /// and is skipped entirely, so decorated grids parse the same as bare
+    /// ones.
+    pub fn from_str_with(s: &str, blanks: &[char]) -> Result<Board<3>, ParseBoardError> {
+        let mut values = Vec::with_capacity(Self::DSIZE * Self::DSIZE);
+
+        for line in s.lines() {
+            if !line.chars().any(|char| char.is_ascii_digit() || blanks.contains(&char)) {
+                continue;
+            }
+
+            for char in line.chars() {
+                if blanks.contains(&char) {
+                    values.push(0);
+                } else if char.is_whitespace() {
+                    continue;
+                } else {
+                    match char.to_digit(10) {
+                        Some(digit) if digit >= 1 && digit as usize <= Self::DSIZE => {
+                            values.push(digit as u8)
+                        }
+                        Some(digit) => return Err(ParseBoardError::OutOfRange { digit: digit as u8 }),
+                        None => return Err(ParseBoardError::InvalidChar { char }),
+                    }
+                }
+            }
+        }
+
+        if values.len() != Self::DSIZE * Self::DSIZE {
+            return Err(ParseBoardError::WrongLength { found: values.len() });
+        }
+
+        Ok(Self::from_values(&values))
+    }
+}
+
+fn debug_line(f: &mut Formatter<'_>, size: usize, start: char, line: char, cross: char, alt_cross: char, end: char) -> fmt::Result {
+    let dsize = size * size;
     f.write_char(start)?;
-    for i in 0..DSIZE {
-        for _ in 0..(SIZE + 2) {
+    for i in 0..dsize {
+        for _ in 0..(size + 2) {
             f.write_char(line)?;
         }
 
         f.write_char(
-            if i == (DSIZE - 1) {
+            if i == (dsize - 1) {
                 end
-            } else if i % SIZE == SIZE - 1 {
+            } else if i % size == size - 1 {
                 alt_cross
             } else {
                 cross
@@ -295,13 +734,15 @@ fn debug_line(f: &mut Formatter<'_>, start: char, line: char, cross: char, alt_c
     f.write_char('\n')
 }
 
-impl Debug for Board {
+impl<const SIZE: usize> Debug for Board<SIZE> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let dsize = Self::DSIZE;
+
         f.write_char('\n')?;
 
-        debug_line(f, '╔', '═', '╤', '╦', '╗')?;
+        debug_line(f, SIZE, '╔', '═', '╤', '╦', '╗')?;
 
-        for (i, row) in self.0.chunks(DSIZE).enumerate() {
+        for (i, row) in self.0.chunks(dsize).enumerate() {
             for cell_y in 0..SIZE {
                 for (j, cell) in row.iter().enumerate() {
                     if j == 0 {
@@ -315,7 +756,7 @@ impl Debug for Board {
                     for cell_x in 0..SIZE {
                         let n = cell_y * SIZE + cell_x;
                         if cell.0 & 1 << n != 0 {
-                            f.write_char(from_digit((n + 1) as u32, 10).unwrap())?;
+                            f.write_char(from_digit((n + 1) as u32, 36).unwrap())?;
                         } else {
                             f.write_char(' ')?;
                         }
@@ -324,27 +765,27 @@ impl Debug for Board {
                 f.write_str(" ║\n")?;
             }
 
-            if i == DSIZE - 1 {
-                debug_line(f, '╚', '═', '╧', '╩', '╝')?;
+            if i == dsize - 1 {
+                debug_line(f, SIZE, '╚', '═', '╧', '╩', '╝')?;
             } else if i % SIZE == SIZE - 1 {
-                debug_line(f, '╠', '═', '╪', '╬', '╣')?;
+                debug_line(f, SIZE, '╠', '═', '╪', '╬', '╣')?;
             } else {
-                debug_line(f, '╟', '─', '┼', '╫', '╢')?;
+                debug_line(f, SIZE, '╟', '─', '┼', '╫', '╢')?;
             }
         }
         Ok(())
     }
 }
 
-fn display_line(f: &mut Formatter<'_>, start: char, line: char, cross: char, end: char) -> fmt::Result {
+fn display_line(f: &mut Formatter<'_>, size: usize, start: char, line: char, cross: char, end: char) -> fmt::Result {
     f.write_char(start)?;
-    for i in 0..SIZE {
-        for _ in 0..(SIZE + 2) {
+    for i in 0..size {
+        for _ in 0..(size + 2) {
             f.write_char(line)?;
         }
 
         f.write_char(
-            if i == (SIZE - 1) {
+            if i == (size - 1) {
                 end
             } else {
                 cross
@@ -354,13 +795,15 @@ fn display_line(f: &mut Formatter<'_>, start: char, line: char, cross: char, end
     f.write_char('\n')
 }
 
-impl Display for Board {
+impl<const SIZE: usize> Display for Board<SIZE> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let dsize = Self::DSIZE;
+
         f.write_char('\n')?;
 
-        display_line(f, '┌', '─', '┬', '┐')?;
+        display_line(f, SIZE, '┌', '─', '┬', '┐')?;
 
-        for (y, row) in self.0.chunks(DSIZE).enumerate() {
+        for (y, row) in self.0.chunks(dsize).enumerate() {
             for (x, cell) in row.iter().enumerate() {
                 if x == 0 {
                     f.write_str("│ ")?;
@@ -369,16 +812,16 @@ impl Display for Board {
                 }
 
                 f.write_char(match cell.value() {
-                    Some(value) => from_digit(value as u32, 10).unwrap(),
+                    Some(value) => from_digit(value as u32, 36).unwrap(),
                     None => ' ',
                 })?;
             }
             f.write_str(" │\n")?;
 
-            if y == DSIZE - 1 {
-                display_line(f, '└', '─', '┴', '┘')?;
+            if y == dsize - 1 {
+                display_line(f, SIZE, '└', '─', '┴', '┘')?;
             } else if y % SIZE == SIZE - 1 {
-                display_line(f, '├', '─', '┼', '┤')?;
+                display_line(f, SIZE, '├', '─', '┼', '┤')?;
             }
         }
         Ok(())
@@ -387,7 +830,7 @@ impl Display for Board {
 
 #[test]
 fn test_solve_rows_valid() {
-    let mut board = Board::from_values(&[
+    let mut board = Board::<3>::from_values(&[
         1, 2, 3, 4, 5, 6, 7, 8, 9,
         0, 2, 3, 4, 5, 6, 7, 8, 9,
         0, 0, 3, 4, 5, 6, 7, 8, 9,
@@ -399,7 +842,7 @@ fn test_solve_rows_valid() {
         0, 0, 0, 0, 0, 0, 0, 0, 9,
     ]);
 
-    let expected = Board::from_bits(&[
+    let expected = Board::<3>::from_bits(&[
         0b000000001, 0b000000010, 0b000000100, 0b000001000, 0b000010000, 0b000100000, 0b001000000, 0b010000000, 0b100000000,
         0b000000001, 0b000000010, 0b000000100, 0b000001000, 0b000010000, 0b000100000, 0b001000000, 0b010000000, 0b100000000,
         0b000000011, 0b000000011, 0b000000100, 0b000001000, 0b000010000, 0b000100000, 0b001000000, 0b010000000, 0b100000000,
@@ -418,7 +861,7 @@ fn test_solve_rows_valid() {
 
 #[test]
 fn test_solve_rows_invalid() {
-    let mut board = Board::from_values(&[
+    let mut board = Board::<3>::from_values(&[
         1, 1, 1, 1, 1, 1, 1, 1, 1,
         2, 2, 2, 2, 2, 2, 2, 2, 2,
         3, 3, 3, 3, 3, 3, 3, 3, 3,
@@ -435,7 +878,7 @@ fn test_solve_rows_invalid() {
 
 #[test]
 fn test_solve_columns_valid() {
-    let mut board = Board::from_values(&[
+    let mut board = Board::<3>::from_values(&[
         1, 0, 0, 0, 0, 0, 0, 0, 0,
         2, 2, 0, 0, 0, 0, 0, 0, 0,
         3, 3, 3, 0, 0, 0, 0, 0, 0,
@@ -447,7 +890,7 @@ fn test_solve_columns_valid() {
         9, 9, 9, 9, 9, 9, 9, 9, 9,
     ]);
 
-    let expected = Board::from_bits(&[
+    let expected = Board::<3>::from_bits(&[
         0b000000001, 0b000000001, 0b000000011, 0b000000111, 0b000001111, 0b000011111, 0b000111111, 0b001111111, 0b011111111,
         0b000000010, 0b000000010, 0b000000011, 0b000000111, 0b000001111, 0b000011111, 0b000111111, 0b001111111, 0b011111111,
         0b000000100, 0b000000100, 0b000000100, 0b000000111, 0b000001111, 0b000011111, 0b000111111, 0b001111111, 0b011111111,
@@ -466,7 +909,7 @@ fn test_solve_columns_valid() {
 
 #[test]
 fn test_solve_columns_invalid() {
-    let mut board = Board::from_values(&[
+    let mut board = Board::<3>::from_values(&[
         1, 2, 3, 4, 5, 6, 7, 8, 9,
         1, 2, 3, 4, 5, 6, 7, 8, 9,
         1, 2, 3, 4, 5, 6, 7, 8, 9,
@@ -483,7 +926,7 @@ fn test_solve_columns_invalid() {
 
 #[test]
 fn test_solve_squares_valid() {
-    let mut board = Board::from_values(&[
+    let mut board = Board::<3>::from_values(&[
         1, 2, 3, 0, 2, 3, 0, 0, 3,
         4, 5, 6, 4, 5, 6, 4, 5, 6,
         7, 8, 9, 7, 8, 9, 7, 8, 9,
@@ -495,7 +938,7 @@ fn test_solve_squares_valid() {
         7, 8, 9, 0, 8, 9, 0, 0, 9,
     ]);
 
-    let expected = Board::from_bits(&[
+    let expected = Board::<3>::from_bits(&[
         0b000000001, 0b000000010, 0b000000100, 0b000000001, 0b000000010, 0b000000100, 0b000000011, 0b000000011, 0b000000100,
         0b000001000, 0b000010000, 0b000100000, 0b000001000, 0b000010000, 0b000100000, 0b000001000, 0b000010000, 0b000100000,
         0b001000000, 0b010000000, 0b100000000, 0b001000000, 0b010000000, 0b100000000, 0b001000000, 0b010000000, 0b100000000,
@@ -514,7 +957,7 @@ fn test_solve_squares_valid() {
 
 #[test]
 fn test_solve_squares_invalid() {
-    let mut board = Board::from_values(&[
+    let mut board = Board::<3>::from_values(&[
         1, 1, 1, 2, 2, 2, 3, 3, 3,
         1, 1, 1, 2, 2, 2, 3, 3, 3,
         1, 1, 1, 2, 2, 2, 3, 3, 3,
@@ -531,7 +974,7 @@ fn test_solve_squares_invalid() {
 
 #[test]
 fn test_solved() {
-    let mut board = Board::from_values(&[
+    let mut board = Board::<3>::from_values(&[
         5, 3, 4, 6, 7, 8, 9, 1, 2,
         6, 7, 2, 1, 9, 5, 3, 4, 8,
         1, 9, 8, 3, 4, 2, 5, 6, 7,
@@ -552,24 +995,24 @@ fn test_solved() {
 #[test]
 fn test_guesses() {
     assert_eq!(
-        Cell::all().guesses().collect::<Vec<_>>(),
-        (1..=9).map(|i| Cell::from_value(i)).collect::<Vec<_>>()
+        Cell::<3>::all().guesses().collect::<Vec<_>>(),
+        (1..=9).map(Cell::<3>::from_value).collect::<Vec<_>>()
     );
 
     assert_eq!(
-        Cell::none().guesses().collect::<Vec<_>>(),
+        Cell::<3>::none().guesses().collect::<Vec<_>>(),
         vec![]
     );
 
     assert_eq!(
-        Cell::from_bits(0b11).guesses().collect::<Vec<_>>(),
-        vec![Cell::from_value(1), Cell::from_value(2)]
+        Cell::<3>::from_bits(0b11).guesses().collect::<Vec<_>>(),
+        vec![Cell::<3>::from_value(1), Cell::<3>::from_value(2)]
     );
 }
 
 #[test]
 fn test_board_simple() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         0, 8, 7, 0, 1, 0, 0, 0, 0,
         0, 0, 4, 8, 0, 0, 1, 2, 0,
         0, 0, 1, 7, 0, 5, 6, 0, 9,
@@ -584,7 +1027,7 @@ fn test_board_simple() {
 
 #[test]
 fn test_board_easy() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         1, 0, 4, 0, 0, 0, 3, 0, 6,
         8, 0, 9, 0, 3, 0, 5, 7, 0,
         0, 0, 0, 0, 7, 0, 1, 0, 0,
@@ -599,7 +1042,7 @@ fn test_board_easy() {
 
 #[test]
 fn test_board_hard() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         2, 9, 0, 1, 0, 0, 0, 0, 5,
         0, 7, 0, 0, 5, 0, 0, 0, 0,
         0, 8, 0, 0, 0, 0, 6, 0, 0,
@@ -614,7 +1057,7 @@ fn test_board_hard() {
 
 #[test]
 fn test_board_hard2() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         8, 0, 0, 5, 9, 0, 3, 0, 1,
         0, 2, 0, 7, 0, 0, 8, 0, 0,
         0, 0, 0, 8, 0, 0, 0, 0, 2,
@@ -629,7 +1072,7 @@ fn test_board_hard2() {
 
 #[test]
 fn test_board_evil() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         0, 9, 0, 0, 0, 0, 7, 0, 0,
         0, 0, 0, 0, 1, 0, 0, 0, 8,
         0, 2, 0, 6, 0, 9, 0, 0, 0,
@@ -645,7 +1088,7 @@ fn test_board_evil() {
 
 #[test]
 fn test_board_evil2() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         2, 0, 0, 0, 8, 5, 0, 9, 1,
         0, 0, 0, 2, 0, 0, 0, 7, 0,
         0, 0, 6, 0, 0, 0, 0, 0, 5,
@@ -658,9 +1101,40 @@ fn test_board_evil2() {
     ]).solve().unwrap();
 }
 
+#[test]
+fn test_board_worlds_hardest() {
+    // Arto Inkala's 2012 "world's hardest sudoku", used here as a
+    // regression test for the minimum-remaining-value branching heuristic.
+    let board = Board::<3>::from_values(&[
+        8, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 3, 6, 0, 0, 0, 0, 0,
+        0, 7, 0, 0, 9, 0, 2, 0, 0,
+        0, 5, 0, 0, 0, 7, 0, 0, 0,
+        0, 0, 0, 0, 4, 5, 7, 0, 0,
+        0, 0, 0, 1, 0, 0, 0, 3, 0,
+        0, 0, 1, 0, 0, 0, 0, 6, 8,
+        0, 0, 8, 5, 0, 0, 0, 1, 0,
+        0, 9, 0, 0, 0, 0, 4, 0, 0,
+    ]).solve().unwrap();
+
+    let expected = Board::<3>::from_values(&[
+        8, 1, 2, 7, 5, 3, 6, 4, 9,
+        9, 4, 3, 6, 8, 2, 1, 7, 5,
+        6, 7, 5, 4, 9, 1, 2, 8, 3,
+        1, 5, 4, 2, 3, 7, 8, 9, 6,
+        3, 6, 9, 8, 4, 5, 7, 2, 1,
+        2, 8, 7, 1, 6, 9, 5, 3, 4,
+        5, 2, 1, 9, 7, 4, 3, 6, 8,
+        4, 3, 8, 5, 2, 6, 9, 1, 7,
+        7, 9, 6, 3, 1, 8, 4, 5, 2,
+    ]);
+
+    assert_eq!(board, expected);
+}
+
 #[test]
 fn test_board_erica() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         9, 0, 3, 0, 2, 0, 0, 7, 0,
         0, 6, 0, 0, 0, 0, 0, 2, 0,
         7, 0, 0, 0, 0, 9, 3, 0, 0,
@@ -675,7 +1149,7 @@ fn test_board_erica() {
 
 #[test]
 fn test_board_test() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         9, 0, 3, 0, 2, 0, 0, 7, 0,
         1, 6, 0, 0, 0, 0, 0, 2, 0,
         7, 0, 0, 0, 0, 9, 3, 0, 0,
@@ -690,7 +1164,7 @@ fn test_board_test() {
 
 #[test]
 fn test_board_empty() {
-    Board::from_values(&[
+    Board::<3>::from_values(&[
         0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -703,9 +1177,114 @@ fn test_board_empty() {
     ]).solve().unwrap();
 }
 
+#[test]
+fn test_from_str_valid() {
+    let board: Board<3> = "\
+        310 000 020
+        000 701 000
+        000 003 700
+        800 500 090
+        000 000 080
+        030 004 600
+        009 003 000
+        000 208 000
+        050 000 026"
+        .parse()
+        .unwrap();
+
+    let expected = Board::<3>::from_values(&[
+        3, 1, 0, 0, 0, 0, 0, 2, 0,
+        0, 0, 0, 7, 0, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 3, 7, 0, 0,
+        8, 0, 0, 5, 0, 0, 0, 9, 0,
+        0, 0, 0, 0, 0, 0, 0, 8, 0,
+        0, 3, 0, 0, 0, 4, 6, 0, 0,
+        0, 0, 9, 0, 0, 3, 0, 0, 0,
+        0, 0, 0, 2, 0, 8, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 2, 6,
+    ]);
+
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn test_from_str_with_ascii_art_separators() {
+    let board: Board<3> = "\
+        310 000 020
+        000 701 000
+        000 003 700
+        ------+-------+------
+        800 500 090
+        000 000 080
+        030 004 600
+        ------+-------+------
+        009 003 000
+        000 208 000
+        050 000 026"
+        .parse()
+        .unwrap();
+
+    let expected = Board::<3>::from_values(&[
+        3, 1, 0, 0, 0, 0, 0, 2, 0,
+        0, 0, 0, 7, 0, 1, 0, 0, 0,
+        0, 0, 0, 0, 0, 3, 7, 0, 0,
+        8, 0, 0, 5, 0, 0, 0, 9, 0,
+        0, 0, 0, 0, 0, 0, 0, 8, 0,
+        0, 3, 0, 0, 0, 4, 6, 0, 0,
+        0, 0, 9, 0, 0, 3, 0, 0, 0,
+        0, 0, 0, 2, 0, 8, 0, 0, 0,
+        0, 5, 0, 0, 0, 0, 0, 2, 6,
+    ]);
+
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn test_from_str_dot_and_underscore_blanks() {
+    let board: Board<3> = "53._7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79"
+        .parse()
+        .unwrap();
+
+    assert_eq!(board.0[2], Cell::<3>::all());
+    assert_eq!(board.0[3], Cell::<3>::all());
+    assert_eq!(board.0[0].value(), Some(5));
+}
+
+#[test]
+fn test_from_str_wrong_length() {
+    assert_eq!(
+        "123".parse::<Board<3>>(),
+        Err(ParseBoardError::WrongLength { found: 3 })
+    );
+}
+
+#[test]
+fn test_from_str_invalid_char() {
+    assert_eq!(
+        Board::from_str_with(&"0".repeat(80).chars().chain(['x']).collect::<String>(), &DEFAULT_BLANKS),
+        Err(ParseBoardError::InvalidChar { char: 'x' })
+    );
+}
+
+#[test]
+fn test_from_str_out_of_range() {
+    let s = "0".repeat(80) + "0";
+    assert_eq!(
+        Board::from_str_with(&s, &['.', '_']),
+        Err(ParseBoardError::OutOfRange { digit: 0 })
+    );
+}
+
+#[test]
+fn test_from_str_with_space_blanks() {
+    let s = " ".repeat(81);
+    let board = Board::from_str_with(&s, &['0', '.', '_', ' ']).unwrap();
+    assert_eq!(board, Board::<3>::from_values(&[0; 81]));
+}
+
 #[test]
 fn test_board_unsolvable() {
-    let board = Board::from_values(&[
+    let board = Board::<3>::from_values(&[
         1, 1, 1, 1, 1, 1, 1, 1, 1,
         1, 1, 1, 1, 1, 1, 1, 1, 1,
         1, 1, 1, 1, 1, 1, 1, 1, 1,
@@ -718,3 +1297,228 @@ fn test_board_unsolvable() {
     ]);
     assert_eq!(board.clone().solve(), Err(board));
 }
+
+#[test]
+fn test_board_4x4() {
+    let board = Board::<2>::from_values(&[
+        1, 0, 0, 4,
+        0, 4, 1, 0,
+        0, 1, 4, 0,
+        4, 0, 0, 1,
+    ]).solve().unwrap();
+
+    let expected = Board::<2>::from_values(&[
+        1, 2, 3, 4,
+        3, 4, 1, 2,
+        2, 1, 4, 3,
+        4, 3, 2, 1,
+    ]);
+
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn test_board_16x16() {
+    Board::<4>::from_values(&[
+        1, 2, 3, 4,  5, 6, 7, 8,  9, 10, 11, 12,  13, 14, 15, 16,
+        5, 6, 7, 8,  9, 10, 11, 12,  13, 14, 15, 16,  1, 2, 3, 4,
+        9, 10, 11, 12,  13, 14, 15, 16,  1, 2, 3, 4,  5, 6, 7, 8,
+        13, 14, 15, 16,  1, 2, 3, 4,  5, 6, 7, 8,  9, 10, 11, 12,
+
+        2, 1, 4, 3,  6, 5, 8, 7,  10, 9, 12, 11,  14, 13, 16, 15,
+        6, 5, 8, 7,  10, 9, 12, 11,  14, 13, 16, 15,  2, 1, 4, 3,
+        10, 9, 12, 11,  14, 13, 16, 15,  2, 1, 4, 3,  6, 5, 8, 7,
+        14, 13, 16, 15,  2, 1, 4, 3,  6, 5, 8, 7,  10, 9, 12, 11,
+
+        3, 4, 1, 2,  7, 8, 5, 6,  11, 12, 9, 10,  15, 16, 13, 14,
+        7, 8, 5, 6,  11, 12, 9, 10,  15, 16, 13, 14,  3, 4, 1, 2,
+        11, 12, 9, 10,  15, 16, 13, 14,  3, 4, 1, 2,  7, 8, 5, 6,
+        15, 16, 13, 14,  3, 4, 1, 2,  7, 8, 5, 6,  11, 12, 9, 10,
+
+        4, 3, 2, 1,  8, 7, 6, 5,  12, 11, 10, 9,  16, 15, 14, 13,
+        8, 7, 6, 5,  12, 11, 10, 9,  16, 15, 14, 13,  4, 3, 2, 1,
+        12, 11, 10, 9,  16, 15, 14, 13,  4, 3, 2, 1,  8, 7, 6, 5,
+        16, 15, 14, 13,  4, 3, 2, 1,  8, 7, 6, 5,  12, 11, 10, 9,
+    ]).solve().unwrap();
+}
+
+#[test]
+fn test_board_25x25() {
+    // SIZE=5 is the largest size whose values (up to 25) still fit the
+    // base-36 glyph `Debug`/`Display` render each cell as; exercise both.
+    let board = Board::<5>::from_values(&[
+        1, 2, 3, 4, 5,  6, 7, 8, 9, 10,  11, 12, 13, 14, 15,  16, 17, 18, 19, 20,  21, 22, 23, 24, 25,
+        6, 7, 8, 9, 10,  11, 12, 13, 14, 15,  16, 17, 18, 19, 20,  21, 22, 23, 24, 25,  1, 2, 3, 4, 5,
+        11, 12, 13, 14, 15,  16, 17, 18, 19, 20,  21, 22, 23, 24, 25,  1, 2, 3, 4, 5,  6, 7, 8, 9, 10,
+        16, 17, 18, 19, 20,  21, 22, 23, 24, 25,  1, 2, 3, 4, 5,  6, 7, 8, 9, 10,  11, 12, 13, 14, 15,
+        21, 22, 23, 24, 25,  1, 2, 3, 4, 5,  6, 7, 8, 9, 10,  11, 12, 13, 14, 15,  16, 17, 18, 19, 20,
+
+        2, 3, 4, 5, 6,  7, 8, 9, 10, 11,  12, 13, 14, 15, 16,  17, 18, 19, 20, 21,  22, 23, 24, 25, 1,
+        7, 8, 9, 10, 11,  12, 13, 14, 15, 16,  17, 18, 19, 20, 21,  22, 23, 24, 25, 1,  2, 3, 4, 5, 6,
+        12, 13, 14, 15, 16,  17, 18, 19, 20, 21,  22, 23, 24, 25, 1,  2, 3, 4, 5, 6,  7, 8, 9, 10, 11,
+        17, 18, 19, 20, 21,  22, 23, 24, 25, 1,  2, 3, 4, 5, 6,  7, 8, 9, 10, 11,  12, 13, 14, 15, 16,
+        22, 23, 24, 25, 1,  2, 3, 4, 5, 6,  7, 8, 9, 10, 11,  12, 13, 14, 15, 16,  17, 18, 19, 20, 21,
+
+        3, 4, 5, 6, 7,  8, 9, 10, 11, 12,  13, 14, 15, 16, 17,  18, 19, 20, 21, 22,  23, 24, 25, 1, 2,
+        8, 9, 10, 11, 12,  13, 14, 15, 16, 17,  18, 19, 20, 21, 22,  23, 24, 25, 1, 2,  3, 4, 5, 6, 7,
+        13, 14, 15, 16, 17,  18, 19, 20, 21, 22,  23, 24, 25, 1, 2,  3, 4, 5, 6, 7,  8, 9, 10, 11, 12,
+        18, 19, 20, 21, 22,  23, 24, 25, 1, 2,  3, 4, 5, 6, 7,  8, 9, 10, 11, 12,  13, 14, 15, 16, 17,
+        23, 24, 25, 1, 2,  3, 4, 5, 6, 7,  8, 9, 10, 11, 12,  13, 14, 15, 16, 17,  18, 19, 20, 21, 22,
+
+        4, 5, 6, 7, 8,  9, 10, 11, 12, 13,  14, 15, 16, 17, 18,  19, 20, 21, 22, 23,  24, 25, 1, 2, 3,
+        9, 10, 11, 12, 13,  14, 15, 16, 17, 18,  19, 20, 21, 22, 23,  24, 25, 1, 2, 3,  4, 5, 6, 7, 8,
+        14, 15, 16, 17, 18,  19, 20, 21, 22, 23,  24, 25, 1, 2, 3,  4, 5, 6, 7, 8,  9, 10, 11, 12, 13,
+        19, 20, 21, 22, 23,  24, 25, 1, 2, 3,  4, 5, 6, 7, 8,  9, 10, 11, 12, 13,  14, 15, 16, 17, 18,
+        24, 25, 1, 2, 3,  4, 5, 6, 7, 8,  9, 10, 11, 12, 13,  14, 15, 16, 17, 18,  19, 20, 21, 22, 23,
+
+        5, 6, 7, 8, 9,  10, 11, 12, 13, 14,  15, 16, 17, 18, 19,  20, 21, 22, 23, 24,  25, 1, 2, 3, 4,
+        10, 11, 12, 13, 14,  15, 16, 17, 18, 19,  20, 21, 22, 23, 24,  25, 1, 2, 3, 4,  5, 6, 7, 8, 9,
+        15, 16, 17, 18, 19,  20, 21, 22, 23, 24,  25, 1, 2, 3, 4,  5, 6, 7, 8, 9,  10, 11, 12, 13, 14,
+        20, 21, 22, 23, 24,  25, 1, 2, 3, 4,  5, 6, 7, 8, 9,  10, 11, 12, 13, 14,  15, 16, 17, 18, 19,
+        25, 1, 2, 3, 4,  5, 6, 7, 8, 9,  10, 11, 12, 13, 14,  15, 16, 17, 18, 19,  20, 21, 22, 23, 24,
+    ]).solve().unwrap();
+
+    assert!(format!("{}", board).contains('p'));
+    assert!(format!("{:?}", board).contains('p'));
+}
+
+#[test]
+#[should_panic(expected = "SIZE*SIZE <= 35")]
+fn test_board_too_large_to_render_panics() {
+    Board::<6>::from_values(&[0; 36 * 36]);
+}
+
+#[test]
+fn test_count_solutions_unique() {
+    let board = Board::<3>::from_values(&[
+        0, 8, 7, 0, 1, 0, 0, 0, 0,
+        0, 0, 4, 8, 0, 0, 1, 2, 0,
+        0, 0, 1, 7, 0, 5, 6, 0, 9,
+        8, 1, 0, 0, 0, 0, 2, 0, 0,
+        0, 6, 0, 0, 0, 0, 0, 5, 0,
+        0, 0, 9, 0, 0, 0, 0, 6, 4,
+        5, 0, 6, 1, 0, 7, 9, 0, 0,
+        0, 3, 2, 0, 0, 9, 5, 0, 0,
+        0, 0, 0, 0, 6, 0, 4, 7, 0,
+    ]);
+
+    assert_eq!(board.count_solutions(2), 1);
+    assert!(board.is_unique());
+}
+
+#[test]
+fn test_count_solutions_not_unique() {
+    let board = Board::<2>::from_values(&[
+        1, 0, 0, 4,
+        0, 4, 1, 0,
+        0, 1, 4, 0,
+        4, 0, 0, 1,
+    ]);
+
+    assert_eq!(board.count_solutions(10), 2);
+    assert!(!board.is_unique());
+}
+
+#[test]
+fn test_count_solutions_respects_limit() {
+    let board = Board::<2>::from_values(&[
+        1, 0, 0, 4,
+        0, 4, 1, 0,
+        0, 1, 4, 0,
+        4, 0, 0, 1,
+    ]);
+
+    assert_eq!(board.count_solutions(1), 1);
+}
+
+#[test]
+fn test_count_solutions_unsolvable() {
+    // Two cells in the same row both fixed to 1 can never be satisfied.
+    let mut values = [0u8; 81];
+    values[0] = 1;
+    values[1] = 1;
+    let board = Board::<3>::from_values(&values);
+
+    assert_eq!(board.count_solutions(2), 0);
+    assert!(!board.is_unique());
+}
+
+#[test]
+fn test_with_units_custom_replaces_boxes() {
+    // A board whose only configured unit is the four corner cells: once
+    // three corners are filled, the fourth must take the one value missing.
+    // The boxes are not enforced, so cell 5 keeps 1 as a candidate even
+    // though cell 0, in the same box, holds a 1.
+    let mut board = Board::<2>::with_units(
+        &[
+            1, 0, 0, 2,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            3, 0, 0, 0,
+        ],
+        vec![vec![0, 3, 12, 15]],
+    );
+
+    assert_eq!(board.solve_squares().unwrap(), true);
+    assert_eq!(board.0[15].value(), Some(4));
+    assert_eq!(board.0[5], Cell::<2>::all());
+}
+
+#[test]
+fn test_board_x_sudoku_diagonals() {
+    // The classic boxes plus both main diagonals as extra units: an
+    // X-sudoku additionally requires each diagonal to hold every digit once.
+    let mut units = Board::<3>::classic_units();
+    units.push((0..9).map(|i| i * 9 + i).collect());
+    units.push((0..9).map(|i| i * 9 + (8 - i)).collect());
+
+    let board = Board::<3>::with_units(
+        &[
+            0, 9, 8, 0, 0, 0, 6, 0, 0,
+            0, 0, 5, 9, 7, 0, 2, 0, 0,
+            0, 2, 7, 0, 6, 0, 0, 9, 4,
+            0, 4, 0, 1, 0, 0, 0, 0, 0,
+            2, 0, 0, 4, 0, 7, 1, 0, 5,
+            0, 5, 0, 0, 8, 0, 0, 4, 9,
+            0, 7, 0, 0, 0, 2, 5, 1, 6,
+            0, 0, 0, 0, 0, 0, 0, 2, 0,
+            0, 0, 2, 0, 0, 0, 0, 0, 0,
+        ],
+        units,
+    )
+    .solve()
+    .unwrap();
+
+    let expected = Board::<3>::from_values(&[
+        4, 9, 8, 3, 2, 1, 6, 5, 7,
+        3, 6, 5, 9, 7, 4, 2, 8, 1,
+        1, 2, 7, 5, 6, 8, 3, 9, 4,
+        7, 4, 9, 1, 5, 6, 8, 3, 2,
+        2, 8, 3, 4, 9, 7, 1, 6, 5,
+        6, 5, 1, 2, 8, 3, 7, 4, 9,
+        9, 7, 4, 8, 3, 2, 5, 1, 6,
+        8, 1, 6, 7, 4, 5, 9, 2, 3,
+        5, 3, 2, 6, 1, 9, 4, 7, 8,
+    ]);
+
+    assert_eq!(board, expected);
+}
+
+#[test]
+fn test_board_solve_annealing() {
+    let board = Board::<3>::from_values(&[
+        0, 8, 7, 0, 1, 0, 0, 0, 0,
+        0, 0, 4, 8, 0, 0, 1, 2, 0,
+        0, 0, 1, 7, 0, 5, 6, 0, 9,
+        8, 1, 0, 0, 0, 0, 2, 0, 0,
+        0, 6, 0, 0, 0, 0, 0, 5, 0,
+        0, 0, 9, 0, 0, 0, 0, 6, 4,
+        5, 0, 6, 1, 0, 7, 9, 0, 0,
+        0, 3, 2, 0, 0, 9, 5, 0, 0,
+        0, 0, 0, 0, 6, 0, 4, 7, 0,
+    ])
+    .solve_annealing()
+    .unwrap();
+
+    assert!(board.solved());
+}
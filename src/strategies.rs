@@ -0,0 +1,336 @@
+//! Human-style logical deduction strategies run to a fixpoint by
+//! `Board::solve_all` before `Board::solve` falls back to backtracking.
+
+use std::rc::Rc;
+
+use super::{Board, Cell};
+
+impl<const SIZE: usize> Board<SIZE> {
+    /// All units (rows, columns, and the board's configured third unit
+    /// group — the classic boxes, unless [`Board::with_units`] was given
+    /// something else) as lists of cell indices.
+    fn units(&self) -> Vec<Vec<usize>> {
+        let dsize = Self::DSIZE;
+        let mut units = Vec::with_capacity(dsize * 2 + self.1.len());
+
+        for y in 0..dsize {
+            units.push((0..dsize).map(|x| y * dsize + x).collect());
+        }
+
+        for x in 0..dsize {
+            units.push((0..dsize).map(|y| y * dsize + x).collect());
+        }
+
+        units.extend(self.1.iter().cloned());
+
+        units
+    }
+
+    /// Collapses any cell that is the only one in its unit able to hold a
+    /// given candidate, even though the cell itself still has other
+    /// candidates left.
+    pub(crate) fn hidden_singles(&mut self) -> Result<bool, ()> {
+        let mut changed = false;
+
+        for unit in self.units() {
+            for bit in 0..Self::DSIZE {
+                let candidate = Cell::from_bits(1u128 << bit);
+                let mut found = None;
+
+                for &idx in &unit {
+                    if self.0[idx] & candidate != Cell::none() {
+                        if found.is_some() {
+                            found = None;
+                            break;
+                        }
+                        found = Some(idx);
+                    }
+                }
+
+                if let Some(idx) = found {
+                    if self.0[idx].num_candidates() > 1 {
+                        self.0[idx] = candidate;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Clears a candidate set shared by exactly N undetermined cells in a
+    /// unit from the other cells in that unit (naked pairs/triples).
+    pub(crate) fn naked_subsets(&mut self) -> Result<bool, ()> {
+        let mut changed = false;
+
+        for unit in self.units() {
+            let undetermined: Vec<usize> = unit
+                .iter()
+                .copied()
+                .filter(|&idx| self.0[idx].num_candidates() > 1)
+                .collect();
+
+            for size in 2..=3 {
+                if undetermined.len() <= size {
+                    continue;
+                }
+
+                for subset in combinations(&undetermined, size) {
+                    let union = subset
+                        .iter()
+                        .fold(Cell::none(), |acc, &idx| acc | self.0[idx]);
+
+                    if union.num_candidates() as usize != size {
+                        continue;
+                    }
+
+                    for &idx in &unit {
+                        if subset.contains(&idx) || self.0[idx].num_candidates() == 1 {
+                            continue;
+                        }
+
+                        let prev = self.0[idx];
+                        let next = prev & !union;
+                        if next == Cell::none() {
+                            return Err(());
+                        }
+                        if next != prev {
+                            self.0[idx] = next;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Pointing pairs and box-line reduction: if a candidate inside one of
+    /// the board's configured units (classically a box) is confined to a
+    /// single row or column, clears it from the rest of that row or column
+    /// outside the unit; and if a candidate inside a row or column is
+    /// confined to a single configured unit, clears it from the rest of
+    /// that unit outside the row or column.
+    pub(crate) fn pointing_pairs(&mut self) -> Result<bool, ()> {
+        let mut changed = false;
+        let dsize = Self::DSIZE;
+        let units = Rc::clone(&self.1);
+
+        for unit in units.iter() {
+            for bit in 0..dsize {
+                let candidate = Cell::from_bits(1u128 << bit);
+
+                // If the candidate is already placed somewhere in this unit,
+                // its location is fixed rather than merely confined to a row
+                // or column within the unit, so there's nothing to infer.
+                if unit.iter().any(|&idx| self.0[idx] == candidate) {
+                    continue;
+                }
+
+                let mut row = None;
+                let mut same_row = true;
+                let mut col = None;
+                let mut same_col = true;
+
+                for &idx in unit {
+                    if self.0[idx].num_candidates() <= 1 || self.0[idx] & candidate == Cell::none() {
+                        continue;
+                    }
+
+                    let this_row = idx / dsize;
+                    let this_col = idx % dsize;
+
+                    match row {
+                        Some(r) if r != this_row => same_row = false,
+                        None => row = Some(this_row),
+                        _ => {}
+                    }
+                    match col {
+                        Some(c) if c != this_col => same_col = false,
+                        None => col = Some(this_col),
+                        _ => {}
+                    }
+                }
+
+                if same_row {
+                    if let Some(row) = row {
+                        for x in 0..dsize {
+                            let idx = row * dsize + x;
+                            if unit.contains(&idx) {
+                                continue;
+                            }
+                            changed |= self.clear_candidate(idx, candidate)?;
+                        }
+                    }
+                }
+
+                if same_col {
+                    if let Some(col) = col {
+                        for y in 0..dsize {
+                            let idx = y * dsize + col;
+                            if unit.contains(&idx) {
+                                continue;
+                            }
+                            changed |= self.clear_candidate(idx, candidate)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        // The reverse direction: rows and columns come first in `units()`.
+        for line in self.units().into_iter().take(2 * dsize) {
+            for bit in 0..dsize {
+                let candidate = Cell::from_bits(1u128 << bit);
+                let holders: Vec<usize> = line.iter().copied().filter(|&idx| self.0[idx] & candidate != Cell::none()).collect();
+                if holders.is_empty() {
+                    continue;
+                }
+                if let Some(unit) = units.iter().find(|unit| holders.iter().all(|idx| unit.contains(idx))) {
+                    for &idx in unit.iter().filter(|&&idx| !line.contains(&idx)) {
+                        changed |= self.clear_candidate(idx, candidate)?;
+                    }
+                }
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Clears `candidate` from an undetermined cell, reporting whether it changed.
+    fn clear_candidate(&mut self, idx: usize, candidate: Cell<SIZE>) -> Result<bool, ()> {
+        if self.0[idx].num_candidates() <= 1 || self.0[idx] & candidate == Cell::none() {
+            return Ok(false);
+        }
+
+        self.0[idx] &= !candidate;
+        if self.0[idx] == Cell::none() {
+            return Err(());
+        }
+        Ok(true)
+    }
+}
+
+/// Returns every `size`-length combination of `items`, preserving order.
+fn combinations(items: &[usize], size: usize) -> Vec<Vec<usize>> {
+    if size == 0 {
+        return vec![Vec::new()];
+    }
+    if items.len() < size {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    for i in 0..=(items.len() - size) {
+        for mut tail in combinations(&items[i + 1..], size - 1) {
+            let mut combo = vec![items[i]];
+            combo.append(&mut tail);
+            result.push(combo);
+        }
+    }
+    result
+}
+
+#[test]
+fn test_hidden_singles() {
+    // Within row 0, every cell but the last can hold any value except 9,
+    // so 9 is a "hidden single" confined to the last (still undetermined) cell.
+    const FULL: u128 = 0b111111111;
+    const NOT_NINE: u128 = 0b011111111;
+
+    let mut board = Board::<3>::from_bits(&[
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+    ]);
+
+    assert_eq!(board.hidden_singles().unwrap(), true);
+    assert_eq!(board.0[8].value(), Some(9));
+}
+
+#[test]
+fn test_naked_subsets() {
+    // Cells 0 and 1 are a naked pair {8, 9}; this should clear those
+    // candidates from cell 2, which starts out able to hold 7, 8, or 9.
+    const FULL: u128 = 0b111111111;
+
+    let mut board = Board::<3>::from_bits(&[
+        0b110000000, 0b110000000, 0b111000000, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+        FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL, FULL,
+    ]);
+
+    assert_eq!(board.naked_subsets().unwrap(), true);
+    assert_eq!(board.0[2], Cell::from_bits(0b001000000));
+}
+
+#[test]
+fn test_pointing_pairs() {
+    // Box 0 has 9 (bit 8) confined to its row-1 cells (9, 10), so pointing
+    // pairs should clear 9 from the rest of row 1 outside the box (12).
+    const FULL: u128 = 0b111111111;
+    const NOT_NINE: u128 = 0b011111111;
+
+    let mut board = Board::<3>::from_bits(&[
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        FULL, FULL, NOT_NINE, FULL, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+    ]);
+
+    assert_eq!(board.pointing_pairs().unwrap(), true);
+    assert_eq!(board.0[12], Cell::from_bits(NOT_NINE));
+}
+
+#[test]
+fn test_pointing_pairs_ignores_unit_where_candidate_is_already_placed() {
+    // Box 0 has 9 already placed at cell 0. Cells 9, 10, 11 still (stale)
+    // carry 9 as a candidate, confined to row 1 within the box, but since
+    // the box already has its 9, that confinement must not be used to
+    // clear 9 from row 1 elsewhere (cell 15, which genuinely needs it).
+    const FULL: u128 = 0b111111111;
+    const NOT_NINE: u128 = 0b011111111;
+    const PLACED_NINE: u128 = 0b100000000;
+
+    let mut board = Board::<3>::from_bits(&[
+        PLACED_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        FULL, FULL, FULL, NOT_NINE, NOT_NINE, NOT_NINE, FULL, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+        NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE, NOT_NINE,
+    ]);
+
+    board.pointing_pairs().unwrap();
+    assert_eq!(board.0[15], Cell::from_bits(FULL));
+}
+
+#[test]
+fn test_solve_logical_stuck_without_backtracking() {
+    // An empty board has no forced logical moves at all, so
+    // `solve_logical` should report it as unsolved rather than guess.
+    let board = Board::<3>::from_values(&[0; 81]);
+    assert!(board.solve_logical().is_err());
+}